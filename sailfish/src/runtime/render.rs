@@ -1,3 +1,6 @@
+use std::net::{
+    IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, SocketAddrV4, SocketAddrV6,
+};
 use std::path::Path;
 
 use super::buffer::Buffer;
@@ -76,18 +79,128 @@ impl Render for char {
 impl Render for Path {
     #[inline]
     fn render(&self, b: &mut Buffer) -> Result<(), RenderError> {
-        // TODO: speed up on Windows using OsStrExt
-        b.push_str(&*self.to_string_lossy());
-        Ok(())
+        render_path(self, b, false)
+    }
+
+    #[inline]
+    fn render_escaped(&self, b: &mut Buffer) -> Result<(), RenderError> {
+        render_path(self, b, true)
+    }
+}
+
+/// Renders a [`Path`] but returns a [`RenderError`] instead of lossily
+/// substituting `U+FFFD` when the path is not valid Unicode, for
+/// applications that must not corrupt filesystem paths.
+pub struct RenderPathStrict<'a>(pub &'a Path);
+
+impl<'a> Render for RenderPathStrict<'a> {
+    #[inline]
+    fn render(&self, b: &mut Buffer) -> Result<(), RenderError> {
+        render_path_strict(self.0, b, false)
     }
 
     #[inline]
     fn render_escaped(&self, b: &mut Buffer) -> Result<(), RenderError> {
-        escape::escape_to_buf(&*self.to_string_lossy(), b);
+        render_path_strict(self.0, b, true)
+    }
+}
+
+#[inline]
+fn emit_segment(b: &mut Buffer, s: &str, escape: bool) {
+    if escape {
+        escape::escape_to_buf(s, b);
+    } else {
+        b.push_str(s);
+    }
+}
+
+/// Push the valid UTF-8 segments of `path` into the buffer, allocating
+/// nothing on the common all-UTF-8 case and substituting `U+FFFD` for
+/// genuinely invalid segments.
+fn render_path(path: &Path, b: &mut Buffer, escape: bool) -> Result<(), RenderError> {
+    #[cfg(unix)]
+    {
+        use std::os::unix::ffi::OsStrExt;
+
+        let mut bytes = path.as_os_str().as_bytes();
+        loop {
+            match std::str::from_utf8(bytes) {
+                Ok(s) => {
+                    emit_segment(b, s, escape);
+                    break;
+                }
+                Err(e) => {
+                    let valid = e.valid_up_to();
+                    if valid != 0 {
+                        emit_segment(
+                            b,
+                            unsafe { std::str::from_utf8_unchecked(&bytes[..valid]) },
+                            escape,
+                        );
+                    }
+                    emit_segment(b, "\u{fffd}", escape);
+                    match e.error_len() {
+                        Some(len) => bytes = &bytes[valid + len..],
+                        None => break,
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    #[cfg(windows)]
+    {
+        use std::os::windows::ffi::OsStrExt;
+
+        let units = path.as_os_str().encode_wide();
+        let mut buf = [0u8; 4];
+        for unit in std::char::decode_utf16(units) {
+            let c = unit.unwrap_or(std::char::REPLACEMENT_CHARACTER);
+            emit_segment(b, c.encode_utf8(&mut buf), escape);
+        }
+        Ok(())
+    }
+
+    #[cfg(not(any(unix, windows)))]
+    {
+        emit_segment(b, &path.to_string_lossy(), escape);
         Ok(())
     }
 }
 
+/// Like [`render_path`] but returns a [`RenderError`] on the first invalid
+/// segment instead of substituting `U+FFFD`.
+fn render_path_strict(
+    path: &Path,
+    b: &mut Buffer,
+    escape: bool,
+) -> Result<(), RenderError> {
+    #[cfg(unix)]
+    {
+        use std::os::unix::ffi::OsStrExt;
+
+        match std::str::from_utf8(path.as_os_str().as_bytes()) {
+            Ok(s) => {
+                emit_segment(b, s, escape);
+                Ok(())
+            }
+            Err(_) => Err(RenderError::new("path is not valid UTF-8")),
+        }
+    }
+
+    #[cfg(not(unix))]
+    {
+        match path.to_str() {
+            Some(s) => {
+                emit_segment(b, s, escape);
+                Ok(())
+            }
+            None => Err(RenderError::new("path is not valid UTF-8")),
+        }
+    }
+}
+
 // impl Render for [u8] {
 //     #[inline]
 //     fn render(&self, b: &mut Buffer) -> Result<(), RenderError> {
@@ -182,6 +295,590 @@ macro_rules! render_float {
 
 render_float!(f32, f64);
 
+/// Renders an integer in lowercase hexadecimal (e.g. `<%= Hex(flags) %>`
+/// &rarr; `ff`).
+pub struct Hex<T>(pub T);
+
+/// Renders an integer in uppercase hexadecimal (e.g. `<%= UpperHex(flags) %>`
+/// &rarr; `FF`).
+pub struct UpperHex<T>(pub T);
+
+/// Renders an integer in octal (e.g. `<%= Oct(mode) %>` &rarr; `755`).
+pub struct Oct<T>(pub T);
+
+/// Renders an integer in binary (e.g. `<%= Bin(flags) %>` &rarr; `1010`).
+pub struct Bin<T>(pub T);
+
+macro_rules! render_radix {
+    ($wrapper:ident, $mask:expr, $shift:expr, $alpha:expr, $($int:ty),*) => {
+        $(
+            impl Render for $wrapper<$int> {
+                #[inline]
+                fn render(&self, b: &mut Buffer) -> Result<(), RenderError> {
+                    const ALPHA: &[u8; 16] = $alpha;
+
+                    // a u64 in binary is the widest case at 64 digits
+                    let mut tmp = [0u8; 64];
+                    let mut v = self.0;
+                    let mut i = tmp.len();
+                    loop {
+                        i -= 1;
+                        tmp[i] = ALPHA[(v & $mask) as usize];
+                        v >>= $shift;
+                        if v == 0 {
+                            break;
+                        }
+                    }
+                    b.push_str(unsafe { std::str::from_utf8_unchecked(&tmp[i..]) });
+                    Ok(())
+                }
+
+                #[inline]
+                fn render_escaped(&self, b: &mut Buffer) -> Result<(), RenderError> {
+                    self.render(b)
+                }
+            }
+        )*
+    }
+}
+
+render_radix!(Hex, 0xf, 4, b"0123456789abcdef", u8, u16, u32, u64, usize);
+render_radix!(UpperHex, 0xf, 4, b"0123456789ABCDEF", u8, u16, u32, u64, usize);
+render_radix!(Oct, 0x7, 3, b"0123456789abcdef", u8, u16, u32, u64, usize);
+render_radix!(Bin, 0x1, 1, b"0123456789abcdef", u8, u16, u32, u64, usize);
+
+/// Renders a float with a fixed number of decimal places, rounding ties to
+/// even (e.g. `<%= Fixed(price, 2) %>` &rarr; `19.99`). A value whose
+/// magnitude times `10^precision` does not fit in a `u128` is rendered in
+/// ryu's shortest form instead, without fixed decimal places.
+pub struct Fixed<T>(pub T, pub usize);
+
+/// Renders a float in scientific notation with a fixed mantissa precision
+/// (e.g. `<%= Sci(n, 2) %>` &rarr; `1.50e3`).
+pub struct Sci<T>(pub T, pub usize);
+
+/// Write `v` in decimal into the buffer using a stack scratch, matching the
+/// allocation-free integer fast path.
+#[inline]
+fn push_u128(b: &mut Buffer, mut v: u128) {
+    let mut tmp = [0u8; 39];
+    let mut i = tmp.len();
+    loop {
+        i -= 1;
+        tmp[i] = b'0' + (v % 10) as u8;
+        v /= 10;
+        if v == 0 {
+            break;
+        }
+    }
+    b.push_str(unsafe { std::str::from_utf8_unchecked(&tmp[i..]) });
+}
+
+/// Write the `prec` low-order decimal digits of `frac` zero-padded on the
+/// left into the buffer (used for the fractional part of a fixed float).
+#[inline]
+fn push_frac(b: &mut Buffer, mut frac: u128, prec: usize) {
+    let mut tmp = [b'0'; 39];
+    let mut i = prec;
+    while frac > 0 {
+        i -= 1;
+        tmp[i] = b'0' + (frac % 10) as u8;
+        frac /= 10;
+    }
+    b.push_str(unsafe { std::str::from_utf8_unchecked(&tmp[..prec]) });
+}
+
+/// `Fixed`/`Sci` compute at most this many fractional digits and pad the
+/// remainder with zeros. A `Sci` mantissa has no meaningful digits past this
+/// point; a `Fixed` value below 1 loses any digits past the 18th place. It
+/// also keeps `10u128.pow(..)` and the `u128` scratch below their
+/// limits so no caller-supplied precision can panic.
+const MAX_FLOAT_PREC: usize = 18;
+
+macro_rules! render_fixed {
+    ($($float:ty),*) => {
+        $(
+            impl Render for Fixed<$float> {
+                #[inline]
+                fn render(&self, b: &mut Buffer) -> Result<(), RenderError> {
+                    let value = self.0 as f64;
+                    let prec = self.1;
+
+                    if value.is_nan() {
+                        b.push_str("NaN");
+                        return Ok(());
+                    }
+                    if value.is_infinite() {
+                        b.push_str(if value < 0.0 { "-inf" } else { "inf" });
+                        return Ok(());
+                    }
+
+                    // cap the significant fraction; trailing places are padded
+                    let eff = prec.min(MAX_FLOAT_PREC);
+                    let scale = 10u128.pow(eff as u32);
+                    let scaled = value.abs() * scale as f64;
+
+                    // magnitudes too large for the integer fast path would
+                    // saturate the `u128` cast, so defer to ryu's shortest form
+                    if scaled >= u128::MAX as f64 {
+                        let mut buffer = ryu::Buffer::new();
+                        b.push_str(buffer.format(self.0));
+                        return Ok(());
+                    }
+
+                    if value.is_sign_negative() {
+                        b.push('-');
+                    }
+
+                    let rounded = scaled.round_ties_even() as u128;
+                    push_u128(b, rounded / scale);
+                    if prec > 0 {
+                        b.push('.');
+                        push_frac(b, rounded % scale, eff);
+                        for _ in eff..prec {
+                            b.push('0');
+                        }
+                    }
+                    Ok(())
+                }
+
+                #[inline]
+                fn render_escaped(&self, b: &mut Buffer) -> Result<(), RenderError> {
+                    self.render(b)
+                }
+            }
+
+            impl Render for Sci<$float> {
+                #[inline]
+                fn render(&self, b: &mut Buffer) -> Result<(), RenderError> {
+                    let mut value = self.0 as f64;
+                    let prec = self.1;
+
+                    if value.is_nan() {
+                        b.push_str("NaN");
+                        return Ok(());
+                    }
+                    if value.is_infinite() {
+                        b.push_str(if value < 0.0 { "-inf" } else { "inf" });
+                        return Ok(());
+                    }
+
+                    if value.is_sign_negative() {
+                        b.push('-');
+                        value = -value;
+                    }
+
+                    // cap the significant fraction; trailing places are padded
+                    let eff = prec.min(MAX_FLOAT_PREC);
+                    let scale = 10u128.pow(eff as u32);
+                    let (mantissa, exp) = if value == 0.0 {
+                        (0u128, 0i32)
+                    } else {
+                        let mut exp = value.log10().floor() as i32;
+                        let mantissa = value / 10f64.powi(exp);
+                        let mut m = (mantissa * scale as f64).round_ties_even() as u128;
+                        // rounding may have bumped the mantissa up to 10.xxx
+                        if m >= 10 * scale {
+                            m /= 10;
+                            exp += 1;
+                        }
+                        (m, exp)
+                    };
+
+                    push_u128(b, mantissa / scale);
+                    if prec > 0 {
+                        b.push('.');
+                        push_frac(b, mantissa % scale, eff);
+                        for _ in eff..prec {
+                            b.push('0');
+                        }
+                    }
+                    b.push('e');
+                    if exp < 0 {
+                        b.push('-');
+                    }
+                    push_u128(b, exp.unsigned_abs() as u128);
+                    Ok(())
+                }
+
+                #[inline]
+                fn render_escaped(&self, b: &mut Buffer) -> Result<(), RenderError> {
+                    self.render(b)
+                }
+            }
+        )*
+    }
+}
+
+render_fixed!(f32, f64);
+
+/// Streams the wrapped bytes into the buffer as lowercase hexadecimal.
+pub struct AsHex<T>(pub T);
+
+/// Streams the wrapped bytes into the buffer as standard (RFC 4648) base64
+/// with `=` padding.
+pub struct AsBase64<T>(pub T);
+
+impl<'a> Render for AsHex<&'a [u8]> {
+    #[inline]
+    fn render(&self, b: &mut Buffer) -> Result<(), RenderError> {
+        const HEX: &[u8; 16] = b"0123456789abcdef";
+
+        // encode in blocks to avoid a push per byte
+        let mut block = [0u8; 64];
+        let mut n = 0;
+        for &byte in self.0 {
+            block[n] = HEX[(byte >> 4) as usize];
+            block[n + 1] = HEX[(byte & 0xf) as usize];
+            n += 2;
+            if n == block.len() {
+                b.push_str(unsafe { std::str::from_utf8_unchecked(&block[..n]) });
+                n = 0;
+            }
+        }
+        if n > 0 {
+            b.push_str(unsafe { std::str::from_utf8_unchecked(&block[..n]) });
+        }
+        Ok(())
+    }
+
+    #[inline]
+    fn render_escaped(&self, b: &mut Buffer) -> Result<(), RenderError> {
+        self.render(b)
+    }
+}
+
+impl<'a> Render for AsBase64<&'a [u8]> {
+    #[inline]
+    fn render(&self, b: &mut Buffer) -> Result<(), RenderError> {
+        const B64: &[u8; 64] =
+            b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+        // blocks are flushed on a 4-char boundary
+        let mut block = [0u8; 64];
+        let mut n = 0;
+        let mut chunks = self.0.chunks_exact(3);
+        for chunk in &mut chunks {
+            let v = (chunk[0] as u32) << 16 | (chunk[1] as u32) << 8 | chunk[2] as u32;
+            block[n] = B64[(v >> 18 & 0x3f) as usize];
+            block[n + 1] = B64[(v >> 12 & 0x3f) as usize];
+            block[n + 2] = B64[(v >> 6 & 0x3f) as usize];
+            block[n + 3] = B64[(v & 0x3f) as usize];
+            n += 4;
+            if n == block.len() {
+                b.push_str(unsafe { std::str::from_utf8_unchecked(&block[..n]) });
+                n = 0;
+            }
+        }
+        if n > 0 {
+            b.push_str(unsafe { std::str::from_utf8_unchecked(&block[..n]) });
+        }
+
+        let rem = chunks.remainder();
+        match rem.len() {
+            1 => {
+                let v = (rem[0] as u32) << 16;
+                let tail = [
+                    B64[(v >> 18 & 0x3f) as usize],
+                    B64[(v >> 12 & 0x3f) as usize],
+                    b'=',
+                    b'=',
+                ];
+                b.push_str(unsafe { std::str::from_utf8_unchecked(&tail) });
+            }
+            2 => {
+                let v = (rem[0] as u32) << 16 | (rem[1] as u32) << 8;
+                let tail = [
+                    B64[(v >> 18 & 0x3f) as usize],
+                    B64[(v >> 12 & 0x3f) as usize],
+                    B64[(v >> 6 & 0x3f) as usize],
+                    b'=',
+                ];
+                b.push_str(unsafe { std::str::from_utf8_unchecked(&tail) });
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    #[inline]
+    fn render_escaped(&self, b: &mut Buffer) -> Result<(), RenderError> {
+        self.render(b)
+    }
+}
+
+/// Write `n` in decimal into `buf` starting at `pos`, returning the new
+/// position. `buf` must have room for the digits (`u16` needs at most 5).
+#[inline]
+fn write_u16_dec(buf: &mut [u8], mut pos: usize, n: u16) -> usize {
+    if n == 0 {
+        buf[pos] = b'0';
+        return pos + 1;
+    }
+    let mut tmp = [0u8; 5];
+    let mut i = 0;
+    let mut v = n;
+    while v > 0 {
+        tmp[i] = b'0' + (v % 10) as u8;
+        v /= 10;
+        i += 1;
+    }
+    while i > 0 {
+        i -= 1;
+        buf[pos] = tmp[i];
+        pos += 1;
+    }
+    pos
+}
+
+/// Write `n` in decimal into `buf` starting at `pos`, returning the new
+/// position (`u32` needs at most 10 digits). Used for the socket scope id.
+#[inline]
+fn write_u32_dec(buf: &mut [u8], mut pos: usize, n: u32) -> usize {
+    if n == 0 {
+        buf[pos] = b'0';
+        return pos + 1;
+    }
+    let mut tmp = [0u8; 10];
+    let mut i = 0;
+    let mut v = n;
+    while v > 0 {
+        tmp[i] = b'0' + (v % 10) as u8;
+        v /= 10;
+        i += 1;
+    }
+    while i > 0 {
+        i -= 1;
+        buf[pos] = tmp[i];
+        pos += 1;
+    }
+    pos
+}
+
+/// Write `n` as lowercase hex with no leading zeros into `buf` starting at
+/// `pos`, returning the new position (a single `0` for `n == 0`).
+#[inline]
+fn write_u16_hex(buf: &mut [u8], mut pos: usize, n: u16) -> usize {
+    const HEX: &[u8; 16] = b"0123456789abcdef";
+    if n == 0 {
+        buf[pos] = b'0';
+        return pos + 1;
+    }
+    let mut tmp = [0u8; 4];
+    let mut i = 0;
+    let mut v = n;
+    while v > 0 {
+        tmp[i] = HEX[(v & 0xf) as usize];
+        v >>= 4;
+        i += 1;
+    }
+    while i > 0 {
+        i -= 1;
+        buf[pos] = tmp[i];
+        pos += 1;
+    }
+    pos
+}
+
+/// Write the canonical RFC 5952 form of `addr` into `buf` starting at `pos`:
+/// lowercase hex, no leading zeros, and the longest run of zero groups
+/// (leftmost on a tie, minimum length two) collapsed to `::`. IPv4-mapped
+/// addresses use the embedded dotted-quad form (RFC 5952 §5), matching
+/// `Ipv6Addr`'s `Display`.
+fn write_ipv6(buf: &mut [u8], mut pos: usize, addr: &Ipv6Addr) -> usize {
+    let segments = addr.segments();
+
+    // RFC 5952 §5: IPv4-mapped addresses render as `::ffff:a.b.c.d`
+    if let [0, 0, 0, 0, 0, 0xffff, g, h] = segments {
+        for &c in b"::ffff:" {
+            buf[pos] = c;
+            pos += 1;
+        }
+        let octets = [(g >> 8) as u8, (g & 0xff) as u8, (h >> 8) as u8, (h & 0xff) as u8];
+        for (i, &octet) in octets.iter().enumerate() {
+            if i != 0 {
+                buf[pos] = b'.';
+                pos += 1;
+            }
+            pos = write_u16_dec(buf, pos, u16::from(octet));
+        }
+        return pos;
+    }
+
+    // find the longest run of consecutive zero groups
+    let (mut best_start, mut best_len) = (0usize, 0usize);
+    let (mut cur_start, mut cur_len) = (0usize, 0usize);
+    for (i, &seg) in segments.iter().enumerate() {
+        if seg == 0 {
+            if cur_len == 0 {
+                cur_start = i;
+            }
+            cur_len += 1;
+            if cur_len > best_len {
+                best_len = cur_len;
+                best_start = cur_start;
+            }
+        } else {
+            cur_len = 0;
+        }
+    }
+
+    // a single zero group is written out in full
+    if best_len < 2 {
+        for (i, &seg) in segments.iter().enumerate() {
+            if i != 0 {
+                buf[pos] = b':';
+                pos += 1;
+            }
+            pos = write_u16_hex(buf, pos, seg);
+        }
+        return pos;
+    }
+
+    for (i, &seg) in segments.iter().take(best_start).enumerate() {
+        if i != 0 {
+            buf[pos] = b':';
+            pos += 1;
+        }
+        pos = write_u16_hex(buf, pos, seg);
+    }
+    buf[pos] = b':';
+    buf[pos + 1] = b':';
+    pos += 2;
+    let after = best_start + best_len;
+    for (i, &seg) in segments.iter().enumerate().skip(after) {
+        if i != after {
+            buf[pos] = b':';
+            pos += 1;
+        }
+        pos = write_u16_hex(buf, pos, seg);
+    }
+    pos
+}
+
+impl Render for Ipv4Addr {
+    #[inline]
+    fn render(&self, b: &mut Buffer) -> Result<(), RenderError> {
+        let mut buf = [0u8; 15];
+        let octets = self.octets();
+        let mut pos = 0;
+        for (i, &octet) in octets.iter().enumerate() {
+            if i != 0 {
+                buf[pos] = b'.';
+                pos += 1;
+            }
+            pos = write_u16_dec(&mut buf, pos, u16::from(octet));
+        }
+        b.push_str(unsafe { std::str::from_utf8_unchecked(&buf[..pos]) });
+        Ok(())
+    }
+
+    #[inline]
+    fn render_escaped(&self, b: &mut Buffer) -> Result<(), RenderError> {
+        // output is always ASCII
+        self.render(b)
+    }
+}
+
+impl Render for Ipv6Addr {
+    #[inline]
+    fn render(&self, b: &mut Buffer) -> Result<(), RenderError> {
+        let mut buf = [0u8; 39];
+        let pos = write_ipv6(&mut buf, 0, self);
+        b.push_str(unsafe { std::str::from_utf8_unchecked(&buf[..pos]) });
+        Ok(())
+    }
+
+    #[inline]
+    fn render_escaped(&self, b: &mut Buffer) -> Result<(), RenderError> {
+        self.render(b)
+    }
+}
+
+impl Render for IpAddr {
+    #[inline]
+    fn render(&self, b: &mut Buffer) -> Result<(), RenderError> {
+        match self {
+            IpAddr::V4(addr) => addr.render(b),
+            IpAddr::V6(addr) => addr.render(b),
+        }
+    }
+
+    #[inline]
+    fn render_escaped(&self, b: &mut Buffer) -> Result<(), RenderError> {
+        self.render(b)
+    }
+}
+
+impl Render for SocketAddrV4 {
+    #[inline]
+    fn render(&self, b: &mut Buffer) -> Result<(), RenderError> {
+        let mut buf = [0u8; 21];
+        let octets = self.ip().octets();
+        let mut pos = 0;
+        for (i, &octet) in octets.iter().enumerate() {
+            if i != 0 {
+                buf[pos] = b'.';
+                pos += 1;
+            }
+            pos = write_u16_dec(&mut buf, pos, u16::from(octet));
+        }
+        buf[pos] = b':';
+        pos += 1;
+        pos = write_u16_dec(&mut buf, pos, self.port());
+        b.push_str(unsafe { std::str::from_utf8_unchecked(&buf[..pos]) });
+        Ok(())
+    }
+
+    #[inline]
+    fn render_escaped(&self, b: &mut Buffer) -> Result<(), RenderError> {
+        self.render(b)
+    }
+}
+
+impl Render for SocketAddrV6 {
+    #[inline]
+    fn render(&self, b: &mut Buffer) -> Result<(), RenderError> {
+        // `[` + 39 byte address + `%` + 10 byte scope id + `]:` + 5 byte port
+        let mut buf = [0u8; 58];
+        buf[0] = b'[';
+        let mut pos = write_ipv6(&mut buf, 1, self.ip());
+        // a non-zero scope id is part of the address, as in `std`'s Display
+        let scope_id = self.scope_id();
+        if scope_id != 0 {
+            buf[pos] = b'%';
+            pos += 1;
+            pos = write_u32_dec(&mut buf, pos, scope_id);
+        }
+        buf[pos] = b']';
+        buf[pos + 1] = b':';
+        pos += 2;
+        pos = write_u16_dec(&mut buf, pos, self.port());
+        b.push_str(unsafe { std::str::from_utf8_unchecked(&buf[..pos]) });
+        Ok(())
+    }
+
+    #[inline]
+    fn render_escaped(&self, b: &mut Buffer) -> Result<(), RenderError> {
+        self.render(b)
+    }
+}
+
+impl Render for SocketAddr {
+    #[inline]
+    fn render(&self, b: &mut Buffer) -> Result<(), RenderError> {
+        match self {
+            SocketAddr::V4(addr) => addr.render(b),
+            SocketAddr::V6(addr) => addr.render(b),
+        }
+    }
+
+    #[inline]
+    fn render_escaped(&self, b: &mut Buffer) -> Result<(), RenderError> {
+        self.render(b)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -234,4 +931,179 @@ mod tests {
 
         assert_eq!(b.as_str(), "ab42.3");
     }
+
+    #[test]
+    fn net_addrs() {
+        let mut b = Buffer::new();
+
+        Ipv4Addr::new(127, 0, 0, 1).render(&mut b).unwrap();
+        assert_eq!(b.as_str(), "127.0.0.1");
+        b.clear();
+
+        // longest zero run collapsed, lowercase, no leading zeros
+        Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 1)
+            .render(&mut b)
+            .unwrap();
+        assert_eq!(b.as_str(), "2001:db8::1");
+        b.clear();
+
+        Ipv6Addr::new(0, 0, 0, 0, 0, 0, 0, 0).render(&mut b).unwrap();
+        assert_eq!(b.as_str(), "::");
+        b.clear();
+
+        Ipv6Addr::new(0, 0, 0, 0, 0, 0, 0, 1).render(&mut b).unwrap();
+        assert_eq!(b.as_str(), "::1");
+        b.clear();
+
+        // a single zero group is not compressed
+        Ipv6Addr::new(1, 0, 1, 1, 1, 1, 1, 1).render(&mut b).unwrap();
+        assert_eq!(b.as_str(), "1:0:1:1:1:1:1:1");
+        b.clear();
+
+        IpAddr::V4(Ipv4Addr::new(192, 168, 0, 255))
+            .render(&mut b)
+            .unwrap();
+        assert_eq!(b.as_str(), "192.168.0.255");
+        b.clear();
+
+        SocketAddrV4::new(Ipv4Addr::new(10, 0, 0, 1), 8080)
+            .render(&mut b)
+            .unwrap();
+        assert_eq!(b.as_str(), "10.0.0.1:8080");
+        b.clear();
+
+        SocketAddrV6::new(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 1), 443, 0, 0)
+            .render(&mut b)
+            .unwrap();
+        assert_eq!(b.as_str(), "[2001:db8::1]:443");
+        b.clear();
+
+        // IPv4-mapped addresses use the embedded dotted-quad form
+        Ipv6Addr::new(0, 0, 0, 0, 0, 0xffff, 0xc0a8, 0x0001)
+            .render(&mut b)
+            .unwrap();
+        assert_eq!(b.as_str(), "::ffff:192.168.0.1");
+        b.clear();
+
+        // a non-zero scope id is included, matching std's Display
+        SocketAddrV6::new(Ipv6Addr::new(0xfe80, 0, 0, 0, 0, 0, 0, 1), 8080, 0, 3)
+            .render(&mut b)
+            .unwrap();
+        assert_eq!(b.as_str(), "[fe80::1%3]:8080");
+        b.clear();
+    }
+
+    #[test]
+    fn radix() {
+        let mut b = Buffer::new();
+
+        Hex(0xffu32).render(&mut b).unwrap();
+        Oct(0o755u32).render(&mut b).unwrap();
+        Bin(0b1010u32).render(&mut b).unwrap();
+        UpperHex(0xdeadu32).render(&mut b).unwrap();
+        assert_eq!(b.as_str(), "ff7551010DEAD");
+        b.clear();
+
+        Hex(0u8).render(&mut b).unwrap();
+        assert_eq!(b.as_str(), "0");
+        b.clear();
+
+        Hex(u64::MAX).render_escaped(&mut b).unwrap();
+        assert_eq!(b.as_str(), "ffffffffffffffff");
+        b.clear();
+    }
+
+    #[test]
+    fn fixed_and_sci() {
+        let mut b = Buffer::new();
+
+        Fixed(19.99f64, 2).render(&mut b).unwrap();
+        assert_eq!(b.as_str(), "19.99");
+        b.clear();
+
+        // round half to even
+        Fixed(2.5f64, 0).render(&mut b).unwrap();
+        Fixed(3.5f64, 0).render(&mut b).unwrap();
+        assert_eq!(b.as_str(), "24");
+        b.clear();
+
+        Fixed(-0.125f64, 2).render(&mut b).unwrap();
+        assert_eq!(b.as_str(), "-0.12");
+        b.clear();
+
+        Sci(1500.0f64, 2).render(&mut b).unwrap();
+        assert_eq!(b.as_str(), "1.50e3");
+        b.clear();
+
+        Sci(0.0f64, 1).render_escaped(&mut b).unwrap();
+        assert_eq!(b.as_str(), "0.0e0");
+        b.clear();
+
+        // out-of-range precision must not panic: capped digits, zero-padded
+        Fixed(1.5f64, 40).render(&mut b).unwrap();
+        assert_eq!(b.as_str(), "1.5000000000000000000000000000000000000000");
+        b.clear();
+
+        // magnitude too large for the fast path falls back to ryu
+        Fixed(1e39f64, 2).render(&mut b).unwrap();
+        assert_eq!(b.as_str(), "1e39");
+        b.clear();
+    }
+
+    #[test]
+    fn binary_adapters() {
+        let mut b = Buffer::new();
+
+        AsHex(&[0x00u8, 0xde, 0xad, 0xbe, 0xef][..])
+            .render(&mut b)
+            .unwrap();
+        assert_eq!(b.as_str(), "00deadbeef");
+        b.clear();
+
+        // the RFC 4648 test vectors exercise both padding lengths
+        AsBase64(&b"f"[..]).render(&mut b).unwrap();
+        assert_eq!(b.as_str(), "Zg==");
+        b.clear();
+
+        AsBase64(&b"fo"[..]).render(&mut b).unwrap();
+        assert_eq!(b.as_str(), "Zm8=");
+        b.clear();
+
+        AsBase64(&b"foobar"[..]).render_escaped(&mut b).unwrap();
+        assert_eq!(b.as_str(), "Zm9vYmFy");
+        b.clear();
+    }
+
+    #[test]
+    fn path_rendering() {
+        use std::path::Path;
+
+        let mut b = Buffer::new();
+        Path::new("dir/file.txt").render(&mut b).unwrap();
+        assert_eq!(b.as_str(), "dir/file.txt");
+        b.clear();
+
+        RenderPathStrict(Path::new("dir/file.txt"))
+            .render(&mut b)
+            .unwrap();
+        assert_eq!(b.as_str(), "dir/file.txt");
+        b.clear();
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn path_invalid_utf8() {
+        use std::ffi::OsStr;
+        use std::os::unix::ffi::OsStrExt;
+        use std::path::Path;
+
+        // 0xff never occurs in valid UTF-8
+        let path = Path::new(OsStr::from_bytes(b"a\xff/b"));
+
+        let mut b = Buffer::new();
+        path.render(&mut b).unwrap();
+        assert_eq!(b.as_str(), "a\u{fffd}/b");
+
+        assert!(RenderPathStrict(path).render(&mut Buffer::new()).is_err());
+    }
 }